@@ -0,0 +1,204 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+use tauri::command;
+use tracing::info;
+
+use crate::python::execute_python_json_command;
+
+/// Which projects to aggregate over.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum AnalyticsScope {
+    Project { project_id: u32 },
+    All,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Analytics {
+    pub total_spend: f64,
+    pub spend_by_category: BTreeMap<String, f64>,
+    pub spend_by_room: BTreeMap<String, f64>,
+    pub total_labor_hours: f64,
+    pub cost_per_sqft: Option<f64>,
+    pub budget_utilization_pct: Option<f64>,
+    pub over_budget: Option<bool>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ExpenseRecord {
+    room_name: String,
+    category: String,
+    cost: f64,
+    #[serde(default)]
+    hours: Option<f64>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ProjectRecord {
+    budget: f64,
+    #[serde(default)]
+    sqft: Option<f64>,
+}
+
+// Backend contract required for this command: the Python CLI must support
+// a `--json` flag on `expense list` and `project show`/`project list` that
+// prints ONE compact (non-pretty-printed) JSON value to stdout — a single
+// object for `project show`, an array for the `list` variants — matching
+// `ExpenseRecord`/`ProjectRecord` above. `execute_python_json_command`
+// only recognizes a JSON value that is the first non-whitespace character
+// on its own output line, so a pretty-printed, multi-line object is
+// invisible to it and surfaces as "No valid JSON found in Python output".
+// No such `--json` flag exists in this repo's Python backend yet; this
+// command cannot be exercised until that lands.
+async fn fetch_expenses(scope: &AnalyticsScope) -> Result<Vec<ExpenseRecord>, String> {
+    let args = match scope {
+        AnalyticsScope::Project { project_id } => {
+            let project_id_str = project_id.to_string();
+            execute_python_json_command(vec!["expense", "list", &project_id_str, "--json"]).await
+        }
+        AnalyticsScope::All => {
+            execute_python_json_command(vec!["expense", "list", "--all", "--json"]).await
+        }
+    };
+
+    args.map_err(|e| format!("Failed to fetch expenses for analytics: {}", e))
+}
+
+/// Combined budget and sqft across the scope's project(s), used to compute
+/// utilization and cost-per-sqft.
+async fn fetch_budget_and_sqft(scope: &AnalyticsScope) -> Result<(f64, Option<f64>), String> {
+    match scope {
+        AnalyticsScope::Project { project_id } => {
+            let project_id_str = project_id.to_string();
+            let project: ProjectRecord =
+                execute_python_json_command(vec!["project", "show", &project_id_str, "--json"])
+                    .await
+                    .map_err(|e| format!("Failed to fetch project {} for analytics: {}", project_id, e))?;
+            Ok((project.budget, project.sqft))
+        }
+        AnalyticsScope::All => {
+            let projects: Vec<ProjectRecord> =
+                execute_python_json_command(vec!["project", "list", "--json"])
+                    .await
+                    .map_err(|e| format!("Failed to fetch projects for analytics: {}", e))?;
+
+            let total_budget = projects.iter().map(|p| p.budget).sum();
+            let total_sqft = projects
+                .iter()
+                .filter_map(|p| p.sqft)
+                .fold(None, |acc: Option<f64>, sqft| Some(acc.unwrap_or(0.0) + sqft));
+
+            Ok((total_budget, total_sqft))
+        }
+    }
+}
+
+/// Roll expense records and a budget/sqft pair up into the `Analytics`
+/// shape. Pulled out of `get_analytics` so the aggregation math is testable
+/// without a Python process in the loop.
+fn aggregate(expenses: &[ExpenseRecord], budget: f64, sqft: Option<f64>) -> Analytics {
+    let mut total_spend = 0.0;
+    let mut total_labor_hours = 0.0;
+    let mut spend_by_category = BTreeMap::new();
+    let mut spend_by_room = BTreeMap::new();
+
+    for expense in expenses {
+        total_spend += expense.cost;
+        total_labor_hours += expense.hours.unwrap_or(0.0);
+        *spend_by_category.entry(expense.category.clone()).or_insert(0.0) += expense.cost;
+        *spend_by_room.entry(expense.room_name.clone()).or_insert(0.0) += expense.cost;
+    }
+
+    let cost_per_sqft = sqft.filter(|s| *s > 0.0).map(|s| total_spend / s);
+    let budget_utilization_pct = if budget > 0.0 {
+        Some(total_spend / budget * 100.0)
+    } else {
+        None
+    };
+    let over_budget = budget_utilization_pct.map(|pct| pct > 100.0);
+
+    Analytics {
+        total_spend,
+        spend_by_category,
+        spend_by_room,
+        total_labor_hours,
+        cost_per_sqft,
+        budget_utilization_pct,
+        over_budget,
+    }
+}
+
+/// Compute cost-per-sqft, category/room breakdowns, and budget utilization
+/// for a project or across every project.
+#[command]
+pub async fn get_analytics(scope: AnalyticsScope) -> Result<Analytics, String> {
+    info!("Computing analytics for scope: {:?}", scope);
+
+    let expenses = fetch_expenses(&scope).await?;
+    let (budget, sqft) = fetch_budget_and_sqft(&scope).await?;
+
+    Ok(aggregate(&expenses, budget, sqft))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn expense(room_name: &str, category: &str, cost: f64, hours: Option<f64>) -> ExpenseRecord {
+        ExpenseRecord {
+            room_name: room_name.to_string(),
+            category: category.to_string(),
+            cost,
+            hours,
+        }
+    }
+
+    #[test]
+    fn totals_and_breakdowns_sum_across_expenses() {
+        let expenses = vec![
+            expense("Kitchen", "material", 100.0, Some(2.0)),
+            expense("Kitchen", "labor", 50.0, Some(1.0)),
+            expense("Bathroom", "material", 25.0, None),
+        ];
+
+        let analytics = aggregate(&expenses, 1000.0, Some(500.0));
+
+        assert_eq!(analytics.total_spend, 175.0);
+        assert_eq!(analytics.total_labor_hours, 3.0);
+        assert_eq!(analytics.spend_by_category.get("material"), Some(&125.0));
+        assert_eq!(analytics.spend_by_category.get("labor"), Some(&50.0));
+        assert_eq!(analytics.spend_by_room.get("Kitchen"), Some(&150.0));
+        assert_eq!(analytics.spend_by_room.get("Bathroom"), Some(&25.0));
+    }
+
+    #[test]
+    fn cost_per_sqft_divides_total_spend_by_sqft() {
+        let expenses = vec![expense("Kitchen", "material", 100.0, None)];
+        let analytics = aggregate(&expenses, 1000.0, Some(50.0));
+        assert_eq!(analytics.cost_per_sqft, Some(2.0));
+    }
+
+    #[test]
+    fn cost_per_sqft_is_none_when_sqft_unknown_or_zero() {
+        let expenses = vec![expense("Kitchen", "material", 100.0, None)];
+        assert_eq!(aggregate(&expenses, 1000.0, None).cost_per_sqft, None);
+        assert_eq!(aggregate(&expenses, 1000.0, Some(0.0)).cost_per_sqft, None);
+    }
+
+    #[test]
+    fn budget_utilization_and_over_budget_flag() {
+        let expenses = vec![expense("Kitchen", "material", 1200.0, None)];
+        let analytics = aggregate(&expenses, 1000.0, None);
+        assert_eq!(analytics.budget_utilization_pct, Some(120.0));
+        assert_eq!(analytics.over_budget, Some(true));
+    }
+
+    #[test]
+    fn budget_utilization_is_none_when_budget_is_zero() {
+        let expenses = vec![expense("Kitchen", "material", 100.0, None)];
+        let analytics = aggregate(&expenses, 0.0, None);
+        assert_eq!(analytics.budget_utilization_pct, None);
+        assert_eq!(analytics.over_budget, None);
+    }
+}
@@ -0,0 +1,206 @@
+use serde::{Deserialize, Serialize};
+use tauri::command;
+use tracing::info;
+
+use crate::commands::{
+    add_expense, add_room, create_project, delete_expense, lookup_project_floors, update_expense,
+    update_room, ExpenseData, ProjectData, RoomData,
+};
+use crate::validation::{self, Diagnostic};
+
+/// One unit of work in a batch. Each variant carries the same payload
+/// structs the single-shot commands already take.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "op", rename_all = "snake_case")]
+pub enum BatchOp {
+    CreateProject {
+        data: ProjectData,
+    },
+    AddRoom {
+        project_id: u32,
+        data: RoomData,
+    },
+    AddExpense {
+        project_id: u32,
+        data: ExpenseData,
+    },
+    UpdateRoom {
+        project_id: u32,
+        room_name: String,
+        data: serde_json::Value,
+    },
+    UpdateExpense {
+        expense_id: u32,
+        data: serde_json::Value,
+    },
+    DeleteExpense {
+        expense_id: u32,
+    },
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BatchMode {
+    /// Dry-run every op through validation first, aborting before anything
+    /// executes if any op would fail. This is a best-effort preflight, not
+    /// a database transaction: the Python backend has no rollback, so if an
+    /// op still fails once execution is underway (e.g. a room update racing
+    /// a delete), ops that already ran are not undone.
+    Atomic,
+    /// Execute sequentially, recording failures but not stopping for them.
+    BestEffort,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BatchResult {
+    pub index: usize,
+    pub ok: bool,
+    pub output_or_error: String,
+}
+
+/// Validation diagnostics for ops that have dedicated rules. Updates and
+/// deletes have no rule set yet, so they always pass the dry run.
+///
+/// `AddRoom` looks up the real, current floor count for `project_id` so the
+/// preflight catches a floor out of range before execution starts. If that
+/// lookup fails it yields `None` and the floor rule is skipped, the same as
+/// in `add_room`.
+async fn dry_run_diagnostics(op: &BatchOp) -> Vec<Diagnostic> {
+    match op {
+        BatchOp::CreateProject { data } => validation::validate_project(data),
+        BatchOp::AddRoom { project_id, data } => {
+            let project_floors = lookup_project_floors(*project_id).await;
+            validation::validate_room(data, project_floors)
+        }
+        BatchOp::AddExpense { data, .. } => validation::validate_expense(data),
+        BatchOp::UpdateRoom { .. } | BatchOp::UpdateExpense { .. } | BatchOp::DeleteExpense { .. } => {
+            Vec::new()
+        }
+    }
+}
+
+async fn apply_op(op: BatchOp) -> Result<String, String> {
+    match op {
+        BatchOp::CreateProject { data } => create_project(data).await.map(|o| o.output),
+        BatchOp::AddRoom { project_id, data } => add_room(project_id, data).await.map(|o| o.output),
+        BatchOp::AddExpense { project_id, data } => {
+            add_expense(project_id, data).await.map(|o| o.output)
+        }
+        BatchOp::UpdateRoom {
+            project_id,
+            room_name,
+            data,
+        } => update_room(project_id, room_name, data).await,
+        BatchOp::UpdateExpense { expense_id, data } => update_expense(expense_id, data).await,
+        BatchOp::DeleteExpense { expense_id } => delete_expense(expense_id).await,
+    }
+}
+
+/// Apply a batch of operations, either atomically (validate everything
+/// first, then run) or best-effort (keep going past failures).
+#[command]
+pub async fn batch_apply(ops: Vec<BatchOp>, mode: BatchMode) -> Result<Vec<BatchResult>, String> {
+    info!("Applying batch of {} operations in {:?} mode", ops.len(), mode);
+
+    if matches!(mode, BatchMode::Atomic) {
+        for (index, op) in ops.iter().enumerate() {
+            let diagnostics = dry_run_diagnostics(op).await;
+            if validation::has_errors(&diagnostics) {
+                return Err(format!(
+                    "Batch aborted: op {} failed validation: {}",
+                    index,
+                    serde_json::to_string(&diagnostics).unwrap_or_default()
+                ));
+            }
+        }
+    }
+
+    let mut results = Vec::with_capacity(ops.len());
+    for (index, op) in ops.into_iter().enumerate() {
+        match apply_op(op).await {
+            Ok(output) => results.push(BatchResult {
+                index,
+                ok: true,
+                output_or_error: output,
+            }),
+            Err(error) => {
+                let is_atomic = matches!(mode, BatchMode::Atomic);
+                results.push(BatchResult {
+                    index,
+                    ok: false,
+                    output_or_error: error,
+                });
+                if is_atomic {
+                    break;
+                }
+            }
+        }
+    }
+
+    Ok(results)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn valid_project_op() -> BatchOp {
+        BatchOp::CreateProject {
+            data: ProjectData {
+                name: "Flip".to_string(),
+                budget: 10_000.0,
+                property_type: "house".to_string(),
+                property_class: "residential".to_string(),
+                description: None,
+                floors: Some(2),
+                sqft: Some(1200.0),
+                address: None,
+            },
+        }
+    }
+
+    fn invalid_expense_op() -> BatchOp {
+        BatchOp::AddExpense {
+            project_id: 1,
+            data: ExpenseData {
+                room_name: "Kitchen".to_string(),
+                category: "material".to_string(),
+                cost: -50.0,
+                hours: None,
+                condition: None,
+                notes: None,
+            },
+        }
+    }
+
+    #[tokio::test]
+    async fn valid_create_project_op_has_no_dry_run_errors() {
+        let diagnostics = dry_run_diagnostics(&valid_project_op()).await;
+        assert!(!validation::has_errors(&diagnostics));
+    }
+
+    #[tokio::test]
+    async fn negative_cost_expense_op_fails_dry_run() {
+        let diagnostics = dry_run_diagnostics(&invalid_expense_op()).await;
+        assert!(validation::has_errors(&diagnostics));
+    }
+
+    #[tokio::test]
+    async fn update_and_delete_ops_have_no_rules_and_always_pass_dry_run() {
+        let ops = vec![
+            BatchOp::UpdateRoom {
+                project_id: 1,
+                room_name: "Kitchen".to_string(),
+                data: serde_json::json!({}),
+            },
+            BatchOp::UpdateExpense {
+                expense_id: 1,
+                data: serde_json::json!({}),
+            },
+            BatchOp::DeleteExpense { expense_id: 1 },
+        ];
+        for op in &ops {
+            assert!(!validation::has_errors(&dry_run_diagnostics(op).await));
+        }
+    }
+}
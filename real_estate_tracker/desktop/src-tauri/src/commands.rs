@@ -4,7 +4,8 @@ use anyhow::Result;
 use tracing::info;
 use tracing::error;
 
-use crate::python::execute_python_command;
+use crate::python::{execute_python_command, execute_python_json_command};
+use crate::validation::{self, Diagnostic};
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct AppInfo {
@@ -56,6 +57,35 @@ pub struct ExpenseData {
     pub notes: Option<String>,
 }
 
+/// Successful command output paired with any non-blocking diagnostics the
+/// validation layer produced along the way.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ValidatedOutput {
+    pub output: String,
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+/// Only the fields we need out of `project show` to cross-check a room.
+#[derive(Debug, Deserialize)]
+struct ProjectFloorsLookup {
+    floors: Option<u32>,
+}
+
+/// Best-effort lookup of a project's floor count, used by room validation.
+/// Returns `None` if the project can't be found or the CLI output can't be
+/// parsed as JSON, rather than failing the whole command.
+pub(crate) async fn lookup_project_floors(project_id: u32) -> Option<u32> {
+    let project_id_str = project_id.to_string();
+    let result: Result<ProjectFloorsLookup, _> =
+        execute_python_json_command(vec!["project", "show", &project_id_str, "--json"]).await;
+    result.ok().and_then(|p| p.floors)
+}
+
+/// Serialize diagnostics as the `Err` branch of a blocked command.
+fn diagnostics_to_err(diagnostics: Vec<Diagnostic>) -> String {
+    serde_json::to_string(&diagnostics).unwrap_or_else(|_| "Validation failed".to_string())
+}
+
 /// Get application information
 #[command]
 pub async fn get_app_info() -> Result<AppInfo, String> {
@@ -105,9 +135,14 @@ pub async fn get_project(project_id: u32) -> Result<String, String> {
 
 /// Create a new project
 #[command]
-pub async fn create_project(data: ProjectData) -> Result<String, String> {
+pub async fn create_project(data: ProjectData) -> Result<ValidatedOutput, String> {
     info!("Creating project: {}", data.name);
-    
+
+    let diagnostics = validation::validate_project(&data);
+    if validation::has_errors(&diagnostics) {
+        return Err(diagnostics_to_err(diagnostics));
+    }
+
     let budget_str = data.budget.to_string();
     let mut args = vec![
         "project", "create",
@@ -160,7 +195,7 @@ pub async fn create_project(data: ProjectData) -> Result<String, String> {
         .await
         .map_err(|e| format!("Failed to create project: {}", e))?;
 
-    Ok(output)
+    Ok(ValidatedOutput { output, diagnostics })
 }
 
 /// Update an existing project
@@ -269,9 +304,15 @@ pub async fn get_rooms(project_id: u32) -> Result<String, String> {
 
 /// Add a room to a project
 #[command]
-pub async fn add_room(project_id: u32, data: RoomData) -> Result<String, String> {
+pub async fn add_room(project_id: u32, data: RoomData) -> Result<ValidatedOutput, String> {
     info!("Adding room {} to project {}", data.name, project_id);
-    
+
+    let project_floors = lookup_project_floors(project_id).await;
+    let diagnostics = validation::validate_room(&data, project_floors);
+    if validation::has_errors(&diagnostics) {
+        return Err(diagnostics_to_err(diagnostics));
+    }
+
     let project_id_str = project_id.to_string();
     let floor_str = data.floor.to_string();
     
@@ -343,7 +384,7 @@ pub async fn add_room(project_id: u32, data: RoomData) -> Result<String, String>
         .await
         .map_err(|e| format!("Failed to add room: {}", e))?;
 
-    Ok(output)
+    Ok(ValidatedOutput { output, diagnostics })
 }
 
 /// Delete a room
@@ -378,10 +419,15 @@ pub async fn get_expenses(project_id: u32) -> Result<String, String> {
 
 /// Add an expense to a project
 #[command]
-pub async fn add_expense(project_id: u32, data: ExpenseData) -> Result<String, String> {
+pub async fn add_expense(project_id: u32, data: ExpenseData) -> Result<ValidatedOutput, String> {
     info!("Adding expense to project {}", project_id);
     info!("Expense data: {:?}", data);
-    
+
+    let diagnostics = validation::validate_expense(&data);
+    if validation::has_errors(&diagnostics) {
+        return Err(diagnostics_to_err(diagnostics));
+    }
+
     let project_id_str = project_id.to_string();
     let cost_str = data.cost.to_string();
     
@@ -442,7 +488,7 @@ pub async fn add_expense(project_id: u32, data: ExpenseData) -> Result<String, S
         })?;
 
     info!("Expense add output: {}", output);
-    Ok(output)
+    Ok(ValidatedOutput { output, diagnostics })
 }
 
 /// Delete an expense
@@ -588,28 +634,9 @@ pub async fn get_budget_status(project_id: u32) -> Result<String, String> {
     Ok(output)
 }
 
-/// Export project data
-#[command]
-pub async fn export_project(project_id: u32, format: String) -> Result<String, String> {
-    info!("Exporting project {} in {} format", project_id, format);
-    
-    let project_id_str = project_id.to_string();
-    let args = vec!["export", "csv", &project_id_str];
-
-    if format != "csv" {
-        return Err("Only CSV export is currently supported".to_string());
-    }
-
-    let output = execute_python_command(args)
-        .await
-        .map_err(|e| format!("Failed to export project {}: {}", project_id, e))?;
-
-    Ok(output)
-} 
-
 /// Test expense addition with debug info
 #[command]
-pub async fn test_expense_add() -> Result<String, String> {
+pub async fn test_expense_add() -> Result<ValidatedOutput, String> {
     info!("Testing expense addition with known good data");
     
     // Test with hardcoded values that we know work
@@ -654,8 +681,163 @@ pub async fn update_project_priority(project_id: u32, priority: String) -> Resul
 #[command]
 pub async fn get_all_expenses() -> Result<String, String> {
     let args = vec!["expense", "list", "--all"];
-    
+
     execute_python_command(args)
         .await
         .map_err(|e| format!("Failed to get all expenses: {}", e))
-} 
\ No newline at end of file
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SortKey {
+    Cost,
+    Condition,
+    Hours,
+    RoomName,
+    Category,
+}
+
+impl SortKey {
+    fn as_cli_value(&self) -> &'static str {
+        match self {
+            SortKey::Cost => "cost",
+            SortKey::Condition => "condition",
+            SortKey::Hours => "hours",
+            SortKey::RoomName => "room",
+            SortKey::Category => "category",
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ExpenseQuery {
+    pub category: Option<String>,
+    pub room_name: Option<String>,
+    pub cost_min: Option<f64>,
+    pub cost_max: Option<f64>,
+    pub condition: Option<u32>,
+    pub sort_by: Option<SortKey>,
+    #[serde(default)]
+    pub descending: bool,
+    pub limit: u8,
+    pub offset: u32,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PaginatedExpenses {
+    pub items: serde_json::Value,
+    pub total_count: u32,
+}
+
+/// Query expenses with server-side filtering, sorting, and pagination.
+/// `project_id` of `None` queries across all projects, same as
+/// `get_all_expenses`.
+///
+/// Backend contract required for this command: `expense list` must accept
+/// `--category`, `--room`, `--cost-min`, `--cost-max`, `--condition`,
+/// `--sort-by <cost|condition|hours|room|category>`, `--descending`,
+/// `--limit`, `--offset`, and `--json`, and under `--json` must print ONE
+/// compact (non-pretty-printed) JSON object of the exact shape
+/// `{"items": [...], "total_count": N}` — `total_count` being the count
+/// before `--limit`/`--offset` are applied, so the frontend can paginate.
+/// `execute_python_json_command` only recognizes a JSON value that starts
+/// a line by itself, so pretty-printed output is invisible to it. No such
+/// flags exist in this repo's Python backend yet; this command cannot be
+/// exercised until that lands.
+#[command]
+pub async fn query_expenses(
+    project_id: Option<u32>,
+    params: ExpenseQuery,
+) -> Result<PaginatedExpenses, String> {
+    info!("Querying expenses for project {:?}: {:?}", project_id, params);
+
+    let project_id_str = project_id.map(|id| id.to_string());
+    let mut args = vec!["expense", "list"];
+    match &project_id_str {
+        Some(id) => args.push(id),
+        None => args.push("--all"),
+    }
+
+    if let Some(category) = &params.category {
+        args.push("--category");
+        args.push(category);
+    }
+
+    if let Some(room_name) = &params.room_name {
+        args.push("--room");
+        args.push(room_name);
+    }
+
+    // Collect optional string values first to avoid borrowing conflicts
+    let mut temp_strings = Vec::new();
+    let mut cost_min_idx = None;
+    let mut cost_max_idx = None;
+    let mut condition_idx = None;
+
+    if let Some(cost_min) = params.cost_min {
+        temp_strings.push(cost_min.to_string());
+        cost_min_idx = Some(temp_strings.len() - 1);
+    }
+
+    if let Some(cost_max) = params.cost_max {
+        temp_strings.push(cost_max.to_string());
+        cost_max_idx = Some(temp_strings.len() - 1);
+    }
+
+    if let Some(condition) = params.condition {
+        temp_strings.push(condition.to_string());
+        condition_idx = Some(temp_strings.len() - 1);
+    }
+
+    let limit_str = params.limit.to_string();
+    let offset_str = params.offset.to_string();
+
+    if let Some(idx) = cost_min_idx {
+        args.push("--cost-min");
+        args.push(&temp_strings[idx]);
+    }
+
+    if let Some(idx) = cost_max_idx {
+        args.push("--cost-max");
+        args.push(&temp_strings[idx]);
+    }
+
+    if let Some(idx) = condition_idx {
+        args.push("--condition");
+        args.push(&temp_strings[idx]);
+    }
+
+    if let Some(sort_by) = &params.sort_by {
+        args.push("--sort-by");
+        args.push(sort_by.as_cli_value());
+    }
+
+    if params.descending {
+        args.push("--descending");
+    }
+
+    args.push("--limit");
+    args.push(&limit_str);
+    args.push("--offset");
+    args.push(&offset_str);
+
+    args.push("--json");
+
+    execute_python_json_command(args)
+        .await
+        .map_err(|e| format!("Failed to query expenses: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sort_key_maps_to_expected_cli_flags() {
+        assert_eq!(SortKey::Cost.as_cli_value(), "cost");
+        assert_eq!(SortKey::Condition.as_cli_value(), "condition");
+        assert_eq!(SortKey::Hours.as_cli_value(), "hours");
+        assert_eq!(SortKey::RoomName.as_cli_value(), "room");
+        assert_eq!(SortKey::Category.as_cli_value(), "category");
+    }
+}
\ No newline at end of file
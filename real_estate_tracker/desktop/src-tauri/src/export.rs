@@ -0,0 +1,325 @@
+use serde::{Deserialize, Serialize};
+use tauri::command;
+use tokio::fs;
+use tracing::info;
+
+use crate::batch::{batch_apply, BatchMode, BatchOp, BatchResult};
+use crate::commands::{ExpenseData, ProjectData, RoomData};
+use crate::database::get_exports_dir;
+use crate::python::{execute_python_command, execute_python_json_command};
+
+/// The export schema version written into JSON bundles, so an importer can
+/// tell which shape it is reading. `import_project_bundle` does not check it.
+const EXPORT_SCHEMA_VERSION: u32 = 1;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ExportFormat {
+    Csv,
+    Json,
+    Xlsx,
+    Pdf,
+}
+
+impl ExportFormat {
+    fn extension(self) -> &'static str {
+        match self {
+            ExportFormat::Csv => "csv",
+            ExportFormat::Json => "json",
+            ExportFormat::Xlsx => "xlsx",
+            ExportFormat::Pdf => "pdf",
+        }
+    }
+
+    fn cli_subcommand(self) -> &'static str {
+        match self {
+            ExportFormat::Csv => "csv",
+            ExportFormat::Json => "json",
+            ExportFormat::Xlsx => "xlsx",
+            ExportFormat::Pdf => "pdf",
+        }
+    }
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct ExportOptions {
+    /// Where to write the export. Defaults to the app's exports directory.
+    /// Ignored for `Csv`, which the backend writes to a location of its own.
+    pub output_path: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ExportResult {
+    pub file_path: String,
+}
+
+/// A full project snapshot, versioned so it can be re-imported by
+/// `batch_apply` later. `project`, `rooms`, and `expenses` use the exact
+/// payload types `BatchOp::CreateProject`/`AddRoom`/`AddExpense` carry, so
+/// the bundle can be turned straight back into a batch. `budget_status` is
+/// informational only (there is no batch op for it) and is kept as the raw
+/// CLI-reported value.
+#[derive(Debug, Serialize, Deserialize)]
+struct ProjectExportBundle {
+    schema_version: u32,
+    project: ProjectData,
+    rooms: Vec<RoomData>,
+    expenses: Vec<ExpenseData>,
+    budget_status: serde_json::Value,
+}
+
+impl ProjectExportBundle {
+    /// Turn this bundle's rooms and expenses back into the batch ops that
+    /// would recreate them against `target_project_id`. `batch_apply` has
+    /// no way to chain a project id a `CreateProject` op just created into
+    /// later ops in the same batch, so re-creating the project itself is a
+    /// separate step: call `create_project` with `self.project` first (see
+    /// `import_project_bundle`), then pass its id here.
+    fn into_batch_ops(self, target_project_id: u32) -> Vec<BatchOp> {
+        self.rooms
+            .into_iter()
+            .map(|data| BatchOp::AddRoom {
+                project_id: target_project_id,
+                data,
+            })
+            .chain(self.expenses.into_iter().map(|data| BatchOp::AddExpense {
+                project_id: target_project_id,
+                data,
+            }))
+            .collect()
+    }
+}
+
+fn default_output_path(project_id: u32, format: ExportFormat) -> Result<String, String> {
+    let exports_dir = get_exports_dir().map_err(|e| format!("Failed to resolve exports directory: {}", e))?;
+    let path = exports_dir.join(format!("project_{}.{}", project_id, format.extension()));
+    Ok(path.to_string_lossy().to_string())
+}
+
+async fn ensure_parent_dir(path: &str) -> Result<(), String> {
+    if let Some(parent) = std::path::Path::new(path).parent() {
+        fs::create_dir_all(parent)
+            .await
+            .map_err(|e| format!("Failed to create export directory: {}", e))?;
+    }
+    Ok(())
+}
+
+/// Export Csv, Xlsx or Pdf by calling the matching Python CLI subcommand,
+/// which writes the file itself. For Xlsx/Pdf the CLI's stdout is discarded
+/// and `output_path` is returned.
+///
+/// `export csv` predates this command and is the one format already known
+/// to work against the current backend; it is called exactly as it was
+/// before (`export csv <id>`, no `--output`) so this change can't regress
+/// it, and its own stdout — the only thing it ever returned — is passed
+/// through as the result. `--output` is only sent for the new Xlsx/Pdf
+/// subcommands, which have no prior CLI contract to preserve.
+async fn export_via_cli(
+    project_id: u32,
+    format: ExportFormat,
+    output_path: &str,
+) -> Result<String, String> {
+    let project_id_str = project_id.to_string();
+
+    if matches!(format, ExportFormat::Csv) {
+        let output = execute_python_command(vec!["export", "csv", &project_id_str])
+            .await
+            .map_err(|e| format!("Failed to export project {}: {}", project_id, e))?;
+        return Ok(output.trim().to_string());
+    }
+
+    let args = vec![
+        "export",
+        format.cli_subcommand(),
+        &project_id_str,
+        "--output",
+        output_path,
+    ];
+
+    execute_python_command(args)
+        .await
+        .map_err(|e| format!("Failed to export project {}: {}", project_id, e))?;
+
+    Ok(output_path.to_string())
+}
+
+/// Assemble a full project bundle in Rust and write it out as JSON. Each
+/// section is fetched through the backend's `--json` output and parsed
+/// into the same typed structs the single-shot commands use, so the bundle
+/// is a stable, re-importable schema rather than opaque CLI text.
+async fn export_json_bundle(project_id: u32, output_path: &str) -> Result<String, String> {
+    let project_id_str = project_id.to_string();
+
+    let project: ProjectData =
+        execute_python_json_command(vec!["project", "show", &project_id_str, "--json"])
+            .await
+            .map_err(|e| format!("Failed to read project {}: {}", project_id, e))?;
+    let rooms: Vec<RoomData> =
+        execute_python_json_command(vec!["room", "list", &project_id_str, "--json"])
+            .await
+            .map_err(|e| format!("Failed to read rooms for project {}: {}", project_id, e))?;
+    let expenses: Vec<ExpenseData> =
+        execute_python_json_command(vec!["expense", "list", &project_id_str, "--json"])
+            .await
+            .map_err(|e| format!("Failed to read expenses for project {}: {}", project_id, e))?;
+    let budget_status: serde_json::Value =
+        execute_python_json_command(vec!["budget", "status", &project_id_str, "--json"])
+            .await
+            .map_err(|e| format!("Failed to read budget status for project {}: {}", project_id, e))?;
+
+    let bundle = ProjectExportBundle {
+        schema_version: EXPORT_SCHEMA_VERSION,
+        project,
+        rooms,
+        expenses,
+        budget_status,
+    };
+
+    let json = serde_json::to_string_pretty(&bundle)
+        .map_err(|e| format!("Failed to serialize export bundle: {}", e))?;
+
+    ensure_parent_dir(output_path).await?;
+    fs::write(output_path, json)
+        .await
+        .map_err(|e| format!("Failed to write export file: {}", e))?;
+
+    Ok(output_path.to_string())
+}
+
+/// Export a project's data to the given format and return the written
+/// file path. For `Csv`, `file_path` holds the backend's trimmed stdout
+/// instead, and `options.output_path` is not used.
+#[command]
+pub async fn export_project(
+    project_id: u32,
+    format: ExportFormat,
+    options: ExportOptions,
+) -> Result<ExportResult, String> {
+    info!("Exporting project {} as {:?}", project_id, format);
+
+    let output_path = match &options.output_path {
+        Some(path) => path.clone(),
+        None => default_output_path(project_id, format)?,
+    };
+
+    let file_path = match format {
+        ExportFormat::Json => export_json_bundle(project_id, &output_path).await?,
+        ExportFormat::Csv | ExportFormat::Xlsx | ExportFormat::Pdf => {
+            ensure_parent_dir(&output_path).await?;
+            export_via_cli(project_id, format, &output_path).await?
+        }
+    };
+
+    Ok(ExportResult { file_path })
+}
+
+/// Re-import a JSON bundle's rooms and expenses into `target_project_id`
+/// via `batch_apply`. The bundle's own project metadata is not recreated
+/// here, since `batch_apply` has no way to thread the id a `CreateProject`
+/// op would produce into the rest of the batch — call `create_project`
+/// with the bundle's `project` data first if a new project is needed, then
+/// pass its id as `target_project_id`.
+#[command]
+pub async fn import_project_bundle(
+    input_path: String,
+    target_project_id: u32,
+) -> Result<Vec<BatchResult>, String> {
+    info!(
+        "Importing bundle {} into project {}",
+        input_path, target_project_id
+    );
+
+    let contents = fs::read_to_string(&input_path)
+        .await
+        .map_err(|e| format!("Failed to read import file {}: {}", input_path, e))?;
+    let bundle: ProjectExportBundle = serde_json::from_str(&contents)
+        .map_err(|e| format!("Failed to parse import file {}: {}", input_path, e))?;
+
+    let ops = bundle.into_batch_ops(target_project_id);
+    batch_apply(ops, BatchMode::BestEffort).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_bundle() -> ProjectExportBundle {
+        ProjectExportBundle {
+            schema_version: EXPORT_SCHEMA_VERSION,
+            project: ProjectData {
+                name: "Flip".to_string(),
+                budget: 10_000.0,
+                property_type: "house".to_string(),
+                property_class: "residential".to_string(),
+                description: None,
+                floors: Some(2),
+                sqft: Some(1200.0),
+                address: None,
+            },
+            rooms: vec![RoomData {
+                name: "Kitchen".to_string(),
+                floor: 1,
+                length: Some(10.0),
+                width: Some(8.0),
+                height: Some(9.0),
+                condition: Some(3),
+                notes: None,
+            }],
+            expenses: vec![ExpenseData {
+                room_name: "Kitchen".to_string(),
+                category: "material".to_string(),
+                cost: 100.0,
+                hours: Some(2.0),
+                condition: Some(3),
+                notes: None,
+            }],
+            budget_status: serde_json::json!({"remaining": 9900.0}),
+        }
+    }
+
+    #[test]
+    fn extension_matches_format() {
+        assert_eq!(ExportFormat::Csv.extension(), "csv");
+        assert_eq!(ExportFormat::Json.extension(), "json");
+        assert_eq!(ExportFormat::Xlsx.extension(), "xlsx");
+        assert_eq!(ExportFormat::Pdf.extension(), "pdf");
+    }
+
+    #[test]
+    fn default_output_path_uses_project_id_and_extension() {
+        let path = default_output_path(7, ExportFormat::Xlsx).unwrap();
+        assert!(path.ends_with("project_7.xlsx"));
+    }
+
+    #[test]
+    fn into_batch_ops_targets_the_given_project_and_skips_create_project() {
+        let ops = sample_bundle().into_batch_ops(42);
+
+        assert_eq!(ops.len(), 2);
+        match &ops[0] {
+            BatchOp::AddRoom { project_id, data } => {
+                assert_eq!(*project_id, 42);
+                assert_eq!(data.name, "Kitchen");
+            }
+            other => panic!("expected AddRoom, got {:?}", other),
+        }
+        match &ops[1] {
+            BatchOp::AddExpense { project_id, data } => {
+                assert_eq!(*project_id, 42);
+                assert_eq!(data.cost, 100.0);
+            }
+            other => panic!("expected AddExpense, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn bundle_round_trips_through_json() {
+        let bundle = sample_bundle();
+        let json = serde_json::to_string(&bundle).unwrap();
+        let parsed: ProjectExportBundle = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed.schema_version, EXPORT_SCHEMA_VERSION);
+        assert_eq!(parsed.rooms.len(), 1);
+        assert_eq!(parsed.expenses.len(), 1);
+    }
+}
@@ -4,6 +4,10 @@
 mod commands;
 mod python;
 mod database;
+mod validation;
+mod batch;
+mod export;
+mod analytics;
 
 use tauri::{Manager, SystemTrayEvent, SystemTrayMenu, SystemTrayMenuItem, CustomMenuItem};
 use tracing::{info, error};
@@ -77,12 +81,17 @@ fn main() {
             commands::add_expense,
             commands::delete_expense,
             commands::get_budget_status,
-            commands::export_project,
+            export::export_project,
+            export::import_project_bundle,
             python::get_python_path,
             python::check_python_installation,
             python::debug_python_paths,
             python::test_python_execution,
-            commands::test_expense_add
+            python::check_backend_compatibility,
+            commands::test_expense_add,
+            commands::query_expenses,
+            batch::batch_apply,
+            analytics::get_analytics
         ])
         .setup(|app| {
             // Initialize app data directory using Tauri's runtime
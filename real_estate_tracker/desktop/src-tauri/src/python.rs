@@ -11,6 +11,25 @@ pub struct PythonInfo {
     pub has_backend: bool,
 }
 
+/// The frontend's own protocol version, compared against whatever the
+/// Python backend reports.
+const FRONTEND_VERSION: &str = "0.2.0";
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Compatibility {
+    pub frontend_version: String,
+    pub backend_version: String,
+    pub compatible: bool,
+    pub supported_features: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct BackendCapabilities {
+    version: String,
+    #[serde(default)]
+    features: Vec<String>,
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct DebugInfo {
     pub current_dir: String,
@@ -208,6 +227,35 @@ pub async fn check_python_installation() -> Result<PythonInfo, String> {
     })
 }
 
+/// Pre-1.0, minor version bumps are the breaking unit, so compatibility is
+/// compared as major.minor rather than major alone.
+fn major_minor(version: &str) -> Option<(&str, &str)> {
+    let mut parts = version.split('.');
+    Some((parts.next()?, parts.next()?))
+}
+
+/// Report the Python backend's protocol version and feature flags, so the
+/// UI can gate commands (new export formats, `update_project_status`, ...)
+/// that an older backend would silently fail on. `compatible` compares
+/// major.minor versions only; the feature list is passed through as the
+/// backend reports it and is not checked here.
+#[tauri::command]
+pub async fn check_backend_compatibility() -> Result<Compatibility, String> {
+    let capabilities: BackendCapabilities =
+        execute_python_json_command(vec!["version", "--capabilities"])
+            .await
+            .map_err(|e| format!("Failed to query backend capabilities: {}", e))?;
+
+    let compatible = major_minor(&capabilities.version) == major_minor(FRONTEND_VERSION);
+
+    Ok(Compatibility {
+        frontend_version: FRONTEND_VERSION.to_string(),
+        backend_version: capabilities.version,
+        compatible,
+        supported_features: capabilities.features,
+    })
+}
+
 /// Execute a Python CLI command and return the result
 pub async fn execute_python_command(args: Vec<&str>) -> Result<String> {
     let python_path = get_python_path().await
@@ -350,4 +398,24 @@ mod tests {
             println!("Python info: {:?}", info);
         }
     }
+
+    #[test]
+    fn major_minor_matches_on_same_major_and_minor() {
+        assert_eq!(major_minor("0.2.0"), major_minor("0.2.5"));
+    }
+
+    #[test]
+    fn major_minor_mismatches_on_different_minor() {
+        assert_ne!(major_minor("0.1.0"), major_minor("0.2.0"));
+    }
+
+    #[test]
+    fn major_minor_mismatches_on_different_major() {
+        assert_ne!(major_minor("1.2.0"), major_minor("0.2.0"));
+    }
+
+    #[test]
+    fn major_minor_is_none_for_malformed_version() {
+        assert_eq!(major_minor("notaversion"), None);
+    }
 } 
\ No newline at end of file
@@ -0,0 +1,416 @@
+use serde::{Deserialize, Serialize};
+
+use crate::commands::{ExpenseData, ProjectData, RoomData};
+
+/// How serious a diagnostic is. `Error` blocks the command from reaching
+/// Python at all; `Warning`/`Info` are informational and ride along with
+/// a successful response.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    Error,
+    Warning,
+    Info,
+}
+
+/// A single field-level finding produced by a `Rule`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    pub code: &'static str,
+    pub field: String,
+    pub message: String,
+}
+
+impl Diagnostic {
+    fn new(severity: Severity, code: &'static str, field: &str, message: impl Into<String>) -> Self {
+        Diagnostic {
+            severity,
+            code,
+            field: field.to_string(),
+            message: message.into(),
+        }
+    }
+}
+
+/// The payload a rule is being asked to check. A room check also carries the
+/// parent project's floor count, when it could be looked up, so a rule can
+/// compare across entities.
+pub enum ValidationCtx<'a> {
+    Project(&'a ProjectData),
+    Room {
+        data: &'a RoomData,
+        project_floors: Option<u32>,
+    },
+    Expense(&'a ExpenseData),
+}
+
+/// A single lint-style check. Rules only ever look at the fields they care
+/// about and return an empty `Vec` when there is nothing to report.
+pub trait Rule {
+    fn check(&self, ctx: &ValidationCtx) -> Vec<Diagnostic>;
+}
+
+struct BudgetPositiveRule;
+impl Rule for BudgetPositiveRule {
+    fn check(&self, ctx: &ValidationCtx) -> Vec<Diagnostic> {
+        match ctx {
+            ValidationCtx::Project(data) if data.budget <= 0.0 => vec![Diagnostic::new(
+                Severity::Error,
+                "budget-positive",
+                "budget",
+                "Budget must be greater than 0",
+            )],
+            _ => Vec::new(),
+        }
+    }
+}
+
+struct DimensionsPositiveRule;
+impl Rule for DimensionsPositiveRule {
+    fn check(&self, ctx: &ValidationCtx) -> Vec<Diagnostic> {
+        let mut diagnostics = Vec::new();
+        if let ValidationCtx::Room { data, .. } = ctx {
+            if let Some(length) = data.length {
+                if length <= 0.0 {
+                    diagnostics.push(Diagnostic::new(
+                        Severity::Error,
+                        "dimension-positive",
+                        "length",
+                        "Length must be greater than 0",
+                    ));
+                }
+            }
+            if let Some(width) = data.width {
+                if width <= 0.0 {
+                    diagnostics.push(Diagnostic::new(
+                        Severity::Error,
+                        "dimension-positive",
+                        "width",
+                        "Width must be greater than 0",
+                    ));
+                }
+            }
+            if let Some(height) = data.height {
+                if height <= 0.0 {
+                    diagnostics.push(Diagnostic::new(
+                        Severity::Error,
+                        "dimension-positive",
+                        "height",
+                        "Height must be greater than 0",
+                    ));
+                }
+            }
+        }
+        diagnostics
+    }
+}
+
+struct RoomFloorWithinProjectRule;
+impl Rule for RoomFloorWithinProjectRule {
+    fn check(&self, ctx: &ValidationCtx) -> Vec<Diagnostic> {
+        match ctx {
+            ValidationCtx::Room {
+                data,
+                project_floors: Some(floors),
+            } if data.floor > *floors => vec![Diagnostic::new(
+                Severity::Error,
+                "room-floor-range",
+                "floor",
+                format!(
+                    "Floor {} is higher than the project's {} floor(s)",
+                    data.floor, floors
+                ),
+            )],
+            _ => Vec::new(),
+        }
+    }
+}
+
+struct ConditionRangeRule;
+impl Rule for ConditionRangeRule {
+    fn check(&self, ctx: &ValidationCtx) -> Vec<Diagnostic> {
+        let condition = match ctx {
+            ValidationCtx::Room { data, .. } => data.condition,
+            ValidationCtx::Expense(data) => data.condition,
+            ValidationCtx::Project(_) => None,
+        };
+
+        match condition {
+            Some(condition) if !(1..=5).contains(&condition) => vec![Diagnostic::new(
+                Severity::Error,
+                "condition-range",
+                "condition",
+                "Condition must be between 1 and 5",
+            )],
+            _ => Vec::new(),
+        }
+    }
+}
+
+struct CostNonNegativeRule;
+impl Rule for CostNonNegativeRule {
+    fn check(&self, ctx: &ValidationCtx) -> Vec<Diagnostic> {
+        match ctx {
+            ValidationCtx::Expense(data) if data.cost < 0.0 => vec![Diagnostic::new(
+                Severity::Error,
+                "cost-non-negative",
+                "cost",
+                "Cost cannot be negative",
+            )],
+            _ => Vec::new(),
+        }
+    }
+}
+
+struct HoursNonNegativeRule;
+impl Rule for HoursNonNegativeRule {
+    fn check(&self, ctx: &ValidationCtx) -> Vec<Diagnostic> {
+        if let ValidationCtx::Expense(data) = ctx {
+            if let Some(hours) = data.hours {
+                if hours < 0.0 {
+                    return vec![Diagnostic::new(
+                        Severity::Error,
+                        "hours-non-negative",
+                        "hours",
+                        "Hours cannot be negative",
+                    )];
+                }
+            }
+        }
+        Vec::new()
+    }
+}
+
+struct LargeExpenseWarningRule;
+impl Rule for LargeExpenseWarningRule {
+    fn check(&self, ctx: &ValidationCtx) -> Vec<Diagnostic> {
+        match ctx {
+            ValidationCtx::Expense(data) if data.cost >= 50_000.0 => vec![Diagnostic::new(
+                Severity::Warning,
+                "expense-unusually-large",
+                "cost",
+                "This expense is unusually large; double-check the amount",
+            )],
+            _ => Vec::new(),
+        }
+    }
+}
+
+struct ProjectDimensionsRule;
+impl Rule for ProjectDimensionsRule {
+    fn check(&self, ctx: &ValidationCtx) -> Vec<Diagnostic> {
+        let mut diagnostics = Vec::new();
+        if let ValidationCtx::Project(data) = ctx {
+            if let Some(sqft) = data.sqft {
+                if sqft <= 0.0 {
+                    diagnostics.push(Diagnostic::new(
+                        Severity::Error,
+                        "sqft-positive",
+                        "sqft",
+                        "Square footage must be greater than 0",
+                    ));
+                }
+            }
+            if let Some(floors) = data.floors {
+                if floors == 0 {
+                    diagnostics.push(Diagnostic::new(
+                        Severity::Error,
+                        "floors-positive",
+                        "floors",
+                        "Floors must be greater than 0",
+                    ));
+                }
+            }
+        }
+        diagnostics
+    }
+}
+
+fn project_rules() -> Vec<Box<dyn Rule>> {
+    vec![Box::new(BudgetPositiveRule), Box::new(ProjectDimensionsRule)]
+}
+
+fn room_rules() -> Vec<Box<dyn Rule>> {
+    vec![
+        Box::new(DimensionsPositiveRule),
+        Box::new(RoomFloorWithinProjectRule),
+        Box::new(ConditionRangeRule),
+    ]
+}
+
+fn expense_rules() -> Vec<Box<dyn Rule>> {
+    vec![
+        Box::new(CostNonNegativeRule),
+        Box::new(HoursNonNegativeRule),
+        Box::new(ConditionRangeRule),
+        Box::new(LargeExpenseWarningRule),
+    ]
+}
+
+fn run_rules(rules: &[Box<dyn Rule>], ctx: &ValidationCtx) -> Vec<Diagnostic> {
+    rules.iter().flat_map(|rule| rule.check(ctx)).collect()
+}
+
+/// Run every applicable rule for a new project.
+pub fn validate_project(data: &ProjectData) -> Vec<Diagnostic> {
+    run_rules(&project_rules(), &ValidationCtx::Project(data))
+}
+
+/// Run every applicable rule for a new room. `project_floors` should be the
+/// parent project's `floors`, when it is known.
+pub fn validate_room(data: &RoomData, project_floors: Option<u32>) -> Vec<Diagnostic> {
+    run_rules(
+        &room_rules(),
+        &ValidationCtx::Room {
+            data,
+            project_floors,
+        },
+    )
+}
+
+/// Run every applicable rule for a new expense.
+pub fn validate_expense(data: &ExpenseData) -> Vec<Diagnostic> {
+    run_rules(&expense_rules(), &ValidationCtx::Expense(data))
+}
+
+/// Whether any diagnostic in the set is severe enough to block the command.
+pub fn has_errors(diagnostics: &[Diagnostic]) -> bool {
+    diagnostics.iter().any(|d| d.severity == Severity::Error)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn base_project() -> ProjectData {
+        ProjectData {
+            name: "Flip".to_string(),
+            budget: 10_000.0,
+            property_type: "house".to_string(),
+            property_class: "residential".to_string(),
+            description: None,
+            floors: Some(2),
+            sqft: Some(1200.0),
+            address: None,
+        }
+    }
+
+    fn base_room() -> RoomData {
+        RoomData {
+            name: "Kitchen".to_string(),
+            floor: 1,
+            length: Some(10.0),
+            width: Some(8.0),
+            height: Some(9.0),
+            condition: Some(3),
+            notes: None,
+        }
+    }
+
+    fn base_expense() -> ExpenseData {
+        ExpenseData {
+            room_name: "Kitchen".to_string(),
+            category: "material".to_string(),
+            cost: 100.0,
+            hours: Some(2.0),
+            condition: Some(3),
+            notes: None,
+        }
+    }
+
+    #[test]
+    fn valid_project_has_no_errors() {
+        assert!(!has_errors(&validate_project(&base_project())));
+    }
+
+    #[test]
+    fn zero_budget_is_an_error() {
+        let mut data = base_project();
+        data.budget = 0.0;
+        assert!(has_errors(&validate_project(&data)));
+    }
+
+    #[test]
+    fn zero_sqft_is_an_error() {
+        let mut data = base_project();
+        data.sqft = Some(0.0);
+        let diagnostics = validate_project(&data);
+        assert!(has_errors(&diagnostics));
+        assert!(diagnostics.iter().any(|d| d.code == "sqft-positive"));
+    }
+
+    #[test]
+    fn zero_floors_is_an_error() {
+        let mut data = base_project();
+        data.floors = Some(0);
+        let diagnostics = validate_project(&data);
+        assert!(has_errors(&diagnostics));
+        assert!(diagnostics.iter().any(|d| d.code == "floors-positive"));
+    }
+
+    #[test]
+    fn valid_room_has_no_errors() {
+        assert!(!has_errors(&validate_room(&base_room(), Some(2))));
+    }
+
+    #[test]
+    fn room_floor_above_project_floors_is_an_error() {
+        let mut data = base_room();
+        data.floor = 3;
+        let diagnostics = validate_room(&data, Some(2));
+        assert!(has_errors(&diagnostics));
+        assert!(diagnostics.iter().any(|d| d.code == "room-floor-range"));
+    }
+
+    #[test]
+    fn room_floor_check_is_skipped_when_project_floors_unknown() {
+        let mut data = base_room();
+        data.floor = 99;
+        assert!(!has_errors(&validate_room(&data, None)));
+    }
+
+    #[test]
+    fn non_positive_room_dimension_is_an_error() {
+        let mut data = base_room();
+        data.length = Some(0.0);
+        assert!(has_errors(&validate_room(&data, None)));
+    }
+
+    #[test]
+    fn condition_outside_range_is_an_error() {
+        let mut data = base_room();
+        data.condition = Some(6);
+        assert!(has_errors(&validate_room(&data, None)));
+    }
+
+    #[test]
+    fn valid_expense_has_no_errors() {
+        assert!(!has_errors(&validate_expense(&base_expense())));
+    }
+
+    #[test]
+    fn negative_cost_is_an_error() {
+        let mut data = base_expense();
+        data.cost = -1.0;
+        assert!(has_errors(&validate_expense(&data)));
+    }
+
+    #[test]
+    fn negative_hours_is_an_error() {
+        let mut data = base_expense();
+        data.hours = Some(-1.0);
+        assert!(has_errors(&validate_expense(&data)));
+    }
+
+    #[test]
+    fn large_expense_is_a_warning_not_an_error() {
+        let mut data = base_expense();
+        data.cost = 60_000.0;
+        let diagnostics = validate_expense(&data);
+        assert!(!has_errors(&diagnostics));
+        assert!(diagnostics
+            .iter()
+            .any(|d| d.severity == Severity::Warning && d.code == "expense-unusually-large"));
+    }
+}